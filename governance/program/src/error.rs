@@ -0,0 +1,21 @@
+//! Error types
+
+use num_derive::FromPrimitive;
+use thiserror::Error;
+
+/// Errors that may be returned by the Governance program
+#[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
+pub enum GovernanceError {
+    // ... existing errors (InvalidGoverningTokenMint, VoteAlreadyExists, InvalidVote, ...) ...
+    /// A VoteRecord passed to FinalizeVote does not belong to the Proposal being finalized
+    #[error("VoteRecord does not belong to the Proposal")]
+    InvalidVoteRecordForProposal,
+
+    /// The same VoteRecord was passed to FinalizeVote more than once
+    #[error("Duplicate VoteRecord passed to FinalizeVote")]
+    DuplicateVoteRecord,
+
+    /// FinalizeVote was not given every ranked VoteRecord needed to tabulate the winner
+    #[error("Missing ranked VoteRecords required for instant-runoff tabulation")]
+    MissingRankedVoteRecords,
+}
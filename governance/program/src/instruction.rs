@@ -0,0 +1,70 @@
+//! Program instructions
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use crate::state::proposal_transaction::InstructionDataBrief;
+
+/// Instructions supported by the Governance program
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum GovernanceInstruction {
+    // ... existing variants ...
+    /// Appends a batch of instructions to the end of the ProposalTransaction's instructions
+    ///
+    /// This is the batch counterpart of `InsertTransaction`; it appends many
+    /// instructions in a single call so that large Proposals don't require a separate
+    /// transaction per instruction.
+    ///
+    ///   0. `[]` Governance account
+    ///   1. `[]` Proposal account
+    ///   2. `[]` TokenOwnerRecord account of the Proposal owner
+    ///   3. `[signer]` Governance Authority (Token Owner or Governance Delegate)
+    ///   4. `[writable]` ProposalTransaction account
+    ///   5+. `[]` For each instruction in order: its program id account followed by one
+    ///            account per entry in the instruction's `accounts` layout
+    InsertInstructions {
+        /// Instructions to insert, each carrying its own account layout
+        #[allow(dead_code)]
+        instructions: Vec<InstructionDataBrief>,
+    },
+}
+
+/// Creates InsertInstructions instruction to add a batch of instructions to a Proposal
+///
+/// The `instruction_accounts` must be ordered per instruction: each instruction's
+/// program id account followed by one account per entry in its `accounts` layout, so the
+/// processor can partition the flat account slice back into per-instruction groups.
+#[allow(clippy::too_many_arguments)]
+pub fn insert_instructions(
+    program_id: &Pubkey,
+    // Accounts
+    governance: &Pubkey,
+    proposal: &Pubkey,
+    token_owner_record: &Pubkey,
+    governance_authority: &Pubkey,
+    proposal_transaction: &Pubkey,
+    // Args
+    instructions: Vec<InstructionDataBrief>,
+    instruction_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*governance, false),
+        AccountMeta::new_readonly(*proposal, false),
+        AccountMeta::new_readonly(*token_owner_record, false),
+        AccountMeta::new_readonly(*governance_authority, true),
+        AccountMeta::new(*proposal_transaction, false),
+    ];
+
+    accounts.extend(instruction_accounts);
+
+    let instruction = GovernanceInstruction::InsertInstructions { instructions };
+
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: instruction.try_to_vec().unwrap(),
+    }
+}
@@ -0,0 +1,34 @@
+//! Program state processor
+
+mod process_cast_vote;
+mod process_finalize_vote;
+mod process_insert_instruction;
+mod process_relinquish_vote;
+
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+};
+
+use crate::instruction::GovernanceInstruction;
+
+use process_insert_instruction::{process_insert_instruction, process_insert_instructions};
+
+/// Processes an instruction
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    // Use try_from_slice_unchecked to support forward compatibility of newer UI with older program
+    let instruction: GovernanceInstruction =
+        GovernanceInstruction::try_from_slice(input)?;
+
+    match instruction {
+        // ... existing dispatch arms ...
+        GovernanceInstruction::InsertInstructions { instructions } => {
+            msg!("GOVERNANCE-INSTRUCTION: InsertInstructions");
+            process_insert_instructions(program_id, accounts, instructions)
+        }
+    }
+}
@@ -14,14 +14,16 @@ use spl_governance_tools::account::create_and_serialize_account_signed;
 use crate::{
     error::GovernanceError,
     state::{
-        enums::GovernanceAccountType,
+        enums::{GovernanceAccountType, ProposalState},
         governance::get_governance_data_for_realm,
         proposal::get_proposal_data_for_governance_and_governing_mint,
+        ranked_choice::RankedChoice,
         realm::get_realm_data_for_governing_token_mint,
         token_owner_record::{
             get_token_owner_record_data_for_proposal_owner,
             get_token_owner_record_data_for_realm_and_governing_mint,
         },
+        veto::is_veto_tipped,
         vote_record::{get_vote_record_address_seeds, Vote, VoteRecordV2},
     },
 };
@@ -72,12 +74,32 @@ pub fn process_cast_vote(
     )?;
     proposal_data.assert_can_cast_vote(&governance_data.config, clock.unix_timestamp)?;
 
+    // A Veto vote is cast by the governing token population opposite to the one
+    // the Proposal is being decided by, hence the voter's TokenOwnerRecord must be
+    // resolved against the opposite (vetoing) governing mint
+    let vote_governing_token_mint = match &vote {
+        Vote::Veto => {
+            // The vetoing population is the one the Proposal is *not* being decided by.
+            // When the Proposal is decided by the community mint the council vetoes it
+            // and vice-versa.
+            if proposal_data.governing_token_mint == realm_data.community_mint {
+                realm_data
+                    .config
+                    .council_mint
+                    .ok_or(GovernanceError::InvalidGoverningTokenMint)?
+            } else {
+                realm_data.community_mint
+            }
+        }
+        _ => *governing_token_mint_info.key,
+    };
+
     let mut voter_token_owner_record_data =
         get_token_owner_record_data_for_realm_and_governing_mint(
             program_id,
             voter_token_owner_record_info,
             &governance_data.realm,
-            governing_token_mint_info.key,
+            &vote_governing_token_mint,
         )?;
     voter_token_owner_record_data
         .assert_token_owner_or_delegate_is_signer(governance_authority_info)?;
@@ -98,6 +120,22 @@ pub fn process_cast_vote(
     //      This extra deserialisation should be acceptable to keep things simple and encapsulated.
     let realm_config_info = next_account_info(account_info_iter)?; //9
 
+    // For a Veto the Proposal is decided by the *opposite* (vetoing) population, so the
+    // max voter weight the veto threshold is measured against must be resolved from the
+    // vetoing mint - the same mint the voter's TokenOwnerRecord was resolved against -
+    // not the Proposal's own governing mint. For a Veto the client supplies that mint
+    // directly after the realm config account (ahead of any voter-weight addin records);
+    // for every other vote the Proposal's governing mint is used.
+    let vote_governing_token_mint_info = if let Vote::Veto = vote {
+        let vote_governing_token_mint_info = next_account_info(account_info_iter)?;
+        if vote_governing_token_mint_info.key != &vote_governing_token_mint {
+            return Err(GovernanceError::InvalidGoverningTokenMint.into());
+        }
+        vote_governing_token_mint_info
+    } else {
+        governing_token_mint_info
+    };
+
     let voter_weight = voter_token_owner_record_data.resolve_voter_weight(
         program_id,
         realm_config_info,
@@ -110,13 +148,57 @@ pub fn process_cast_vote(
 
     proposal_data.assert_valid_vote(&vote)?;
 
+    // The weight applied to the Proposal tallies depends on the Governance's vote
+    // weight calculation mode. In Quadratic mode the resolved voter_weight is reduced
+    // to its integer square root to dampen whale dominance (note this only mitigates,
+    // it doesn't eliminate, sybil attacks, hence it pairs with the voter-weight addin).
+    // max_voter_weight is deliberately left untransformed: since `sqrt(wᵢ) <= wᵢ` for
+    // every integer weight, the sum of per-voter square-root tallies never exceeds the
+    // linear supply total, so the raw max is the correct upper bound to measure the
+    // thresholds against (square-rooting the supply would make them tip on tiny turnout).
+    let vote_weight = governance_data
+        .config
+        .vote_weight_calculation
+        .apply_weight(voter_weight);
+
+    // For ranked (instant-runoff) Proposals the full ordered ballot is preserved on the
+    // VoteRecord so it can be replayed during IRV tabulation at finalize time. The
+    // per-option vote_weight is still accumulated so participation/quorum checks keep
+    // working regardless of the tabulation mode.
+    let ranked_ballot = if governance_data.config.use_ranked_choice_vote {
+        match &vote {
+            Vote::Approve(choices) => Some(
+                choices
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, choice)| choice.rank > 0)
+                    .map(|(option_index, choice)| RankedChoice {
+                        option_index: option_index as u8,
+                        rank: choice.rank,
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    // Count every ranked ballot which carries at least one preference. FinalizeVote is
+    // permissionless, so it must present exactly this many ranked VoteRecords; the count
+    // is what lets finalize prove no ballot was dropped to steer the IRV winner.
+    if ranked_ballot.as_ref().map_or(false, |choices| !choices.is_empty()) {
+        proposal_data.ranked_vote_count =
+            proposal_data.ranked_vote_count.checked_add(1).unwrap();
+    }
+
     // Calculate Proposal voting weights
     match &vote {
         Vote::Approve(choices) => {
             for (option, choice) in proposal_data.options.iter_mut().zip(choices) {
                 option.vote_weight = option
                     .vote_weight
-                    .checked_add(choice.get_choice_weight(voter_weight)?)
+                    .checked_add(choice.get_choice_weight(vote_weight)?)
                     .unwrap();
             }
         }
@@ -125,29 +207,94 @@ pub fn process_cast_vote(
                 proposal_data
                     .deny_vote_weight
                     .unwrap()
-                    .checked_add(voter_weight)
+                    .checked_add(vote_weight)
                     .unwrap(),
             )
         }
-        Vote::Abstain | Vote::Veto => {
-            return Err(GovernanceError::NotSupportedVoteType.into());
+        Vote::Veto => {
+            proposal_data.veto_vote_weight = Some(
+                proposal_data
+                    .veto_vote_weight
+                    .unwrap_or(0)
+                    .checked_add(vote_weight)
+                    .unwrap(),
+            )
+        }
+        Vote::Abstain => {
+            // Abstain votes don't count towards approval or denial but are tallied
+            // separately so they can contribute to the Proposal's quorum
+            proposal_data.abstain_vote_weight = Some(
+                proposal_data
+                    .abstain_vote_weight
+                    .unwrap_or(0)
+                    .checked_add(vote_weight)
+                    .unwrap(),
+            )
         }
     }
 
+    // max_voter_weight is the linear upper bound on all weight that can participate. The
+    // Quadratic transform is applied per voter as votes are cast (see `vote_weight`
+    // above), and because `Σ sqrt(wᵢ) <= Σ wᵢ` the accumulated quadratic tallies stay
+    // bounded by this linear maximum, so the tipping thresholds remain comparable.
     let max_voter_weight = proposal_data.resolve_max_voter_weight(
         program_id,
         realm_config_info,
-        governing_token_mint_info,
+        vote_governing_token_mint_info,
         account_info_iter, // max_voter_weight_record  11
         realm_info.key,
         &realm_data,
     )?;
 
-    if proposal_data.try_tip_vote(
-        max_voter_weight,
-        &governance_data.config,
-        clock.unix_timestamp,
-    )? {
+    // A Veto is tipped against the vetoing population's threshold and, when crossed,
+    // transitions the Proposal to Vetoed. Regular votes keep using try_tip_vote which
+    // transitions to Succeeded/Defeated.
+    let tipped = if let Vote::Veto = vote {
+        let veto_vote_threshold = if vote_governing_token_mint
+            == realm_data.config.council_mint.unwrap_or_default()
+        {
+            &governance_data.config.council_veto_vote_threshold
+        } else {
+            &governance_data.config.community_veto_vote_threshold
+        };
+
+        if is_veto_tipped(
+            proposal_data.veto_vote_weight.unwrap_or(0),
+            max_voter_weight,
+            veto_vote_threshold,
+        ) {
+            proposal_data.state = ProposalState::Vetoed;
+            proposal_data.voting_completed_at = Some(clock.unix_timestamp);
+            true
+        } else {
+            false
+        }
+    } else {
+        proposal_data.try_tip_vote(
+            max_voter_weight,
+            &governance_data.config,
+            clock.unix_timestamp,
+        )?
+    };
+
+    // A QuorumPercentage Proposal must draw the required turnout before it can tip to
+    // Succeeded; an early tip to Defeated is left as it is.
+    // try_tip_vote decides Succeeded from the approve-vs-deny split alone, so without this
+    // gate a Proposal could tip early - before the abstain weight that counts toward
+    // quorum has arrived - and bypass the abstain-inclusive quorum check FinalizeVote
+    // applies. When the quorum isn't met yet the early tip is undone and voting continues.
+    let tipped = if tipped
+        && proposal_data.state == ProposalState::Succeeded
+        && !proposal_data.is_abstain_quorum_met(max_voter_weight)
+    {
+        proposal_data.state = ProposalState::Voting;
+        proposal_data.voting_completed_at = None;
+        false
+    } else {
+        tipped
+    };
+
+    if tipped {
         // Deserialize proposal owner and validate it's the actual owner of the proposal
         let mut proposal_owner_record_data = get_token_owner_record_data_for_proposal_owner(
             program_id,
@@ -181,14 +328,18 @@ pub fn process_cast_vote(
 
     proposal_data.serialize(&mut *proposal_info.data.borrow_mut())?;
 
-    // Create and serialize VoteRecord
+    // Create and serialize VoteRecord. The weight stored is the one actually applied to
+    // the Proposal tallies (`vote_weight`), i.e. after the Governance's vote weight
+    // transform. Storing the raw `voter_weight` would make IRV tabulation at finalize
+    // weigh ballots in different units than the quorum tallies in a Quadratic realm.
     let vote_record_data = VoteRecordV2 {
         account_type: GovernanceAccountType::VoteRecordV2,
         proposal: *proposal_info.key,
         governing_token_owner,
-        voter_weight,
+        voter_weight: vote_weight,
         vote,
         is_relinquished: false,
+        ranked_ballot,
         reserved_v2: [0; 8],
     };
 
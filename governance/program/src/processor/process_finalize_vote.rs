@@ -0,0 +1,147 @@
+//! Program state processor
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_governance_tools::account::get_account_data;
+
+use crate::{
+    error::GovernanceError,
+    state::{
+        enums::ProposalState,
+        governance::get_governance_data_for_realm,
+        proposal::get_proposal_data_for_governance,
+        ranked_choice::{tabulate_instant_runoff, RankedBallot},
+        realm::get_realm_data,
+        token_owner_record::get_token_owner_record_data_for_proposal_owner,
+        vote_record::{Vote, VoteRecordV2},
+    },
+};
+
+/// Processes FinalizeVote instruction
+pub fn process_finalize_vote(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let realm_info = next_account_info(account_info_iter)?; // 0
+    let governance_info = next_account_info(account_info_iter)?; // 1
+    let proposal_info = next_account_info(account_info_iter)?; // 2
+    let proposal_owner_record_info = next_account_info(account_info_iter)?; // 3
+    let governing_token_mint_info = next_account_info(account_info_iter)?; // 4
+    let realm_config_info = next_account_info(account_info_iter)?; // 5
+
+    let clock = Clock::get()?;
+
+    let realm_data = get_realm_data(program_id, realm_info)?;
+    let mut governance_data =
+        get_governance_data_for_realm(program_id, governance_info, realm_info.key)?;
+
+    let mut proposal_data =
+        get_proposal_data_for_governance(program_id, proposal_info, governance_info.key)?;
+
+    // max_voter_weight is the linear upper bound on all weight that can participate.
+    // The Quadratic transform is applied per voter in cast-vote and, because
+    // `Σ sqrt(wᵢ) <= Σ wᵢ`, the accumulated quadratic tallies stay bounded by this linear
+    // maximum, so it must be resolved raw here (mirroring `process_cast_vote`).
+    let max_voter_weight = proposal_data.resolve_max_voter_weight(
+        program_id,
+        realm_config_info,
+        governing_token_mint_info,
+        account_info_iter,
+        realm_info.key,
+        &realm_data,
+    )?;
+
+    proposal_data.finalize_vote(
+        max_voter_weight,
+        &governance_data.config,
+        clock.unix_timestamp,
+    )?;
+
+    // Fold abstain weight into the quorum determination. `finalize_vote` decides
+    // Succeeded/Defeated purely from the approve-vs-deny split and never folds abstain
+    // weight into that decision, so `is_abstain_quorum_met` is the single source of the
+    // quorum check: a Proposal which reached its approval tally but failed to draw the
+    // required turnout is defeated here.
+    if proposal_data.state == ProposalState::Succeeded
+        && !proposal_data.is_abstain_quorum_met(max_voter_weight)
+    {
+        proposal_data.state = ProposalState::Defeated;
+    }
+
+    // For ranked Proposals the winning option is determined by instant-runoff
+    // tabulation over the ranked ballots recorded on each VoteRecord rather than by
+    // the accumulated per-option weight.
+    if governance_data.config.use_ranked_choice_vote
+        && proposal_data.state == ProposalState::Succeeded
+    {
+        // FinalizeVote is permissionless and the VoteRecords are supplied by the caller,
+        // so the tabulation must not trust the presented set blindly. Each record must
+        // belong to this Proposal, relinquished votes are dropped, and a record presented
+        // more than once is rejected so a single ballot can't be counted twice. The count
+        // of ranked ballots presented is then checked against `ranked_vote_count`
+        // accumulated during cast-vote, which guarantees the complete ballot set is
+        // replayed and no favourable-to-one-option ballot can be selectively omitted.
+        let mut seen = Vec::new();
+        let mut ballots = Vec::new();
+        let mut presented_ranked_vote_count: u64 = 0;
+        for vote_record_info in account_info_iter {
+            if seen.contains(vote_record_info.key) {
+                return Err(GovernanceError::DuplicateVoteRecord.into());
+            }
+            seen.push(*vote_record_info.key);
+
+            let vote_record_data = get_account_data::<VoteRecordV2>(program_id, vote_record_info)?;
+
+            if vote_record_data.proposal != *proposal_info.key {
+                return Err(GovernanceError::InvalidVoteRecordForProposal.into());
+            }
+
+            if vote_record_data.is_relinquished {
+                continue;
+            }
+
+            let choices = match (&vote_record_data.vote, vote_record_data.ranked_ballot) {
+                (Vote::Approve(_), Some(ranked_ballot)) if !ranked_ballot.is_empty() => {
+                    presented_ranked_vote_count =
+                        presented_ranked_vote_count.checked_add(1).unwrap();
+                    ranked_ballot
+                }
+                _ => vec![],
+            };
+
+            ballots.push(RankedBallot {
+                weight: vote_record_data.voter_weight,
+                choices,
+            });
+        }
+
+        if presented_ranked_vote_count != proposal_data.ranked_vote_count {
+            return Err(GovernanceError::MissingRankedVoteRecords.into());
+        }
+
+        if let Some(winning_option_index) =
+            tabulate_instant_runoff(proposal_data.options.len(), &ballots)
+        {
+            proposal_data.set_ranked_choice_winner(winning_option_index);
+        }
+    }
+
+    let mut proposal_owner_record_data = get_token_owner_record_data_for_proposal_owner(
+        program_id,
+        proposal_owner_record_info,
+        &proposal_data.token_owner_record,
+    )?;
+    proposal_owner_record_data.decrease_outstanding_proposal_count();
+    proposal_owner_record_data.serialize(&mut *proposal_owner_record_info.data.borrow_mut())?;
+
+    governance_data.voting_proposal_count = governance_data.voting_proposal_count.saturating_sub(1);
+    governance_data.serialize(&mut *governance_info.data.borrow_mut())?;
+
+    proposal_data.serialize(&mut *proposal_info.data.borrow_mut())?;
+
+    Ok(())
+}
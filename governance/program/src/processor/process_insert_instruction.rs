@@ -73,3 +73,97 @@ pub fn process_insert_instruction(
 
     Ok(())
 }
+
+/// Processes InsertInstructions instruction (a batch of instructions)
+///
+/// This appends many `InstructionData` to the `ProposalTransactionV2` in a single
+/// call, which avoids having to submit a separate transaction per instruction when
+/// assembling large Proposals. The trailing account_infos are partitioned per
+/// instruction using the per-entry account counts (the program id account followed by
+/// its `accounts`) and the whole batch is validated before any mutation, so a
+/// malformed batch leaves the transaction account untouched.
+pub fn process_insert_instructions(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instructions: Vec<InstructionDataBrief>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let governance_info = next_account_info(account_info_iter)?; // 0
+    let proposal_info = next_account_info(account_info_iter)?; // 1
+    let token_owner_record_info = next_account_info(account_info_iter)?; // 2
+    let governance_authority_info = next_account_info(account_info_iter)?; // 3
+
+    let proposal_transaction_info = next_account_info(account_info_iter)?; // 4
+
+    let instruction_accounts = account_info_iter.collect::<Vec<_>>(); // 5..n
+
+    if proposal_transaction_info.data_is_empty() {
+        return Err(GovernanceError::TransactionDoesNotExists.into());
+    }
+
+    let proposal_data =
+        get_proposal_data_for_governance(program_id, proposal_info, governance_info.key)?;
+    proposal_data.assert_can_edit_instructions()?;
+
+    let token_owner_record_data = get_token_owner_record_data_for_proposal_owner(
+        program_id,
+        token_owner_record_info,
+        &proposal_data.token_owner_record,
+    )?;
+
+    token_owner_record_data.assert_token_owner_or_delegate_is_signer(governance_authority_info)?;
+
+    // Validate the whole batch and resolve each instruction's account slice before
+    // mutating anything. Each instruction consumes its program id account plus one
+    // account per entry in its `accounts` layout.
+    let mut account_cursor = 0usize;
+    let mut instructions_data = Vec::with_capacity(instructions.len());
+
+    for instruction in instructions {
+        let program_id_index = account_cursor;
+        let accounts_start = account_cursor
+            .checked_add(1)
+            .ok_or(GovernanceError::InvalidInstructionData)?;
+        let accounts_end = accounts_start
+            .checked_add(instruction.accounts.len())
+            .ok_or(GovernanceError::InvalidInstructionData)?;
+
+        if accounts_end > instruction_accounts.len() {
+            return Err(GovernanceError::InvalidInstructionData.into());
+        }
+
+        let instruction_program_id = instruction_accounts[program_id_index];
+        let instruction_keys = &instruction_accounts[accounts_start..accounts_end];
+
+        instructions_data.push(InstructionData {
+            program_id: instruction_program_id.key.clone(),
+            accounts: instruction_keys
+                .iter()
+                .zip(instruction.accounts.iter())
+                .map(|(account_info, account_metadata)| AccountMetaData {
+                    pubkey: account_info.key.clone(),
+                    is_signer: account_metadata.is_signer,
+                    is_writable: account_metadata.is_writable,
+                })
+                .collect::<Vec<_>>(),
+            data: instruction.data,
+        });
+
+        account_cursor = accounts_end;
+    }
+
+    // All trailing accounts must be consumed exactly by the batch layout
+    if account_cursor != instruction_accounts.len() {
+        return Err(GovernanceError::InvalidInstructionData.into());
+    }
+
+    let mut proposal_transaction =
+        get_account_data::<ProposalTransactionV2>(program_id, proposal_transaction_info)?;
+
+    proposal_transaction.instructions.extend(instructions_data);
+
+    proposal_transaction.serialize(&mut *proposal_transaction_info.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+//! Program state processor
+
+// ... existing imports ...
+
+/// Processes RelinquishVote instruction
+pub fn process_relinquish_vote(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    // ... existing account parsing and validation, resolving proposal_data and
+    //     vote_record_data and asserting the vote can be relinquished ...
+
+    // Keep the Proposal's ranked ballot count in step with the VoteRecords that are still
+    // live. A ranked ballot which carried preferences was counted in cast-vote, so it must
+    // be discounted here; otherwise FinalizeVote could never match `ranked_vote_count`
+    // against the ballots presented once any ranked vote is relinquished.
+    if proposal_data.state == ProposalState::Voting
+        && matches!(&vote_record_data.vote, Vote::Approve(_))
+    {
+        if let Some(ranked_ballot) = &vote_record_data.ranked_ballot {
+            if !ranked_ballot.is_empty() {
+                proposal_data.ranked_vote_count =
+                    proposal_data.ranked_vote_count.saturating_sub(1);
+            }
+        }
+    }
+
+    // ... existing relinquish bookkeeping (clear the vote weight from the tallies, mark the
+    //     VoteRecord relinquished or close it, update the TokenOwnerRecord counts) ...
+
+    Ok(())
+}
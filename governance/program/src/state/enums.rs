@@ -0,0 +1,16 @@
+//! State enumerations
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+
+/// What state a Proposal is in
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum ProposalState {
+    // ... existing states (Draft, SigningOff, Voting, Succeeded, Executing,
+    //     ExecutingWithErrors, Completed, Cancelled, Defeated) ...
+    /// The Proposal was vetoed by the opposite governing token population and can no
+    /// longer be executed. Reached from `Voting` once the veto weight crosses the
+    /// Governance's veto threshold (see `is_veto_tipped`).
+    Vetoed,
+}
+
+// ... existing enums (GovernanceAccountType, VoteThreshold, VoteTipping, ...) ...
@@ -0,0 +1,22 @@
+//! Governance account
+
+use crate::state::{enums::VoteThreshold, vote_weight_calculation::VoteWeightCalculation};
+
+/// Governance config
+pub struct GovernanceConfig {
+    // ... existing fields (community_vote_threshold, min_community_weight_to_create_proposal,
+    //     min_transaction_hold_up_time, max_voting_time, council_vote_threshold, ...) ...
+    /// Veto vote threshold applied when the Council population vetoes a community Proposal
+    pub council_veto_vote_threshold: VoteThreshold,
+
+    /// Veto vote threshold applied when the Community population vetoes a council Proposal
+    pub community_veto_vote_threshold: VoteThreshold,
+
+    /// How a voter's resolved weight is transformed before being applied to the Proposal
+    /// tallies (one-token-one-vote vs. quadratic dampening)
+    pub vote_weight_calculation: VoteWeightCalculation,
+
+    /// When set, multi-option Proposals are decided by instant-runoff tabulation over the
+    /// ranked ballots rather than by the raw per-option weight
+    pub use_ranked_choice_vote: bool,
+}
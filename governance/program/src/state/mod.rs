@@ -0,0 +1,9 @@
+//! Program accounts and state
+
+// ... existing modules (enums, governance, proposal, realm, token_owner_record,
+//     vote_record, proposal_transaction, ...) ...
+
+pub mod quorum;
+pub mod ranked_choice;
+pub mod veto;
+pub mod vote_weight_calculation;
@@ -0,0 +1,80 @@
+//! Proposal account
+
+use solana_program::program_error::ProgramError;
+
+use crate::{
+    error::GovernanceError,
+    state::{
+        enums::VoteThreshold,
+        quorum::{is_quorum_met, participation_weight},
+        vote_record::Vote,
+    },
+};
+
+/// Governance Proposal account V2
+pub struct ProposalV2 {
+    // ... existing fields (account_type, governance, governing_token_mint, state,
+    //     token_owner_record, options, deny_vote_weight, vote_threshold,
+    //     voting_completed_at, ...) ...
+    /// Accumulated Veto vote weight cast by the opposite governing token population.
+    /// `None` until the first Veto is cast (see `process_cast_vote`).
+    pub veto_vote_weight: Option<u64>,
+
+    /// Accumulated Abstain vote weight. Counts toward the Proposal's quorum only.
+    pub abstain_vote_weight: Option<u64>,
+
+    /// Number of ranked ballots carrying at least one preference. FinalizeVote must
+    /// replay exactly this many ranked VoteRecords so the instant-runoff winner can't be
+    /// steered by omitting ballots (see `process_finalize_vote`).
+    pub ranked_vote_count: u64,
+}
+
+impl ProposalV2 {
+    // ... existing methods (assert_can_cast_vote, try_tip_vote, finalize_vote,
+    //     resolve_max_voter_weight, set_ranked_choice_winner, ...) ...
+
+    /// Returns whether the Proposal's abstain-inclusive participation meets its
+    /// `QuorumPercentage` turnout requirement.
+    ///
+    /// Abstain weight counts toward turnout but not toward approval (see
+    /// `crate::state::quorum`), so this is the single source of the quorum check used by
+    /// both the cast-vote early-tip gate and `FinalizeVote`. Thresholds without a turnout
+    /// component (`YesVotePercentage`, `Disabled`) have no quorum and return `true`.
+    pub fn is_abstain_quorum_met(&self, max_voter_weight: u64) -> bool {
+        let quorum_percentage = match self.vote_threshold {
+            Some(VoteThreshold::QuorumPercentage(quorum_percentage)) => quorum_percentage,
+            _ => return true,
+        };
+
+        let approve_vote_weight = self
+            .options
+            .iter()
+            .fold(0u64, |acc, option| acc.saturating_add(option.vote_weight));
+
+        let participation = participation_weight(
+            approve_vote_weight,
+            self.deny_vote_weight.unwrap_or(0),
+            self.abstain_vote_weight.unwrap_or(0),
+        );
+
+        is_quorum_met(participation, max_voter_weight, quorum_percentage)
+    }
+
+    /// Asserts the given `vote` is valid for the Proposal.
+    ///
+    /// `Approve` must provide exactly one choice per option; `Deny`, `Veto` and `Abstain`
+    /// carry no per-option choices and are always structurally valid. `Veto` and
+    /// `Abstain` are accepted in addition to the original `Approve`/`Deny` kinds.
+    pub fn assert_valid_vote(&self, vote: &Vote) -> Result<(), ProgramError> {
+        match vote {
+            Vote::Approve(choices) => {
+                if choices.len() != self.options.len() {
+                    return Err(GovernanceError::InvalidVote.into());
+                }
+            }
+            Vote::Deny | Vote::Veto | Vote::Abstain => {}
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+//! Proposal quorum/participation helpers
+
+/// Returns the total weight which counts toward a Proposal's participation (quorum).
+///
+/// Abstain weight counts toward participation but is deliberately excluded from the
+/// approve-vs-deny determination (that split lives in `try_tip_vote` and `finalize_vote`).
+/// This lets a realm require a minimum turnout without forcing voters to pick a side.
+///
+/// Participation only gates a Proposal under a `QuorumPercentage` threshold; under a
+/// `YesVotePercentage` threshold there is no turnout requirement and abstain weight has
+/// no effect on the outcome.
+pub fn participation_weight(
+    approve_vote_weight: u64,
+    deny_vote_weight: u64,
+    abstain_vote_weight: u64,
+) -> u64 {
+    approve_vote_weight
+        .saturating_add(deny_vote_weight)
+        .saturating_add(abstain_vote_weight)
+}
+
+/// Returns `true` when the `participation_weight` meets `quorum_percentage` of
+/// `max_voter_weight`.
+pub fn is_quorum_met(
+    participation_weight: u64,
+    max_voter_weight: u64,
+    quorum_percentage: u8,
+) -> bool {
+    if max_voter_weight == 0 {
+        return false;
+    }
+
+    let required_weight =
+        (max_voter_weight as u128 * quorum_percentage as u128).div_ceil(100);
+
+    participation_weight as u128 >= required_weight
+}
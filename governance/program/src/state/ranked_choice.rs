@@ -0,0 +1,110 @@
+//! Ranked-choice (instant-runoff) ballots and tabulation
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+
+/// A single entry of a ranked ballot assigning a preference `rank` to a Proposal option.
+///
+/// `rank` follows the 1 = most preferred convention; a `rank` of 0 is treated as
+/// "unranked" and is never stored (see `process_cast_vote`).
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct RankedChoice {
+    /// The index of the Proposal option this entry ranks
+    pub option_index: u8,
+
+    /// The preference rank, 1 = most preferred
+    pub rank: u8,
+}
+
+/// A voter's ranked ballot together with the weight it carries
+pub struct RankedBallot {
+    /// The weight the ballot contributes to whichever option it is currently counted for
+    pub weight: u64,
+
+    /// The ranked choices, in arbitrary order (they are resolved by `rank` during tabulation)
+    pub choices: Vec<RankedChoice>,
+}
+
+impl RankedBallot {
+    /// Returns the index of the ballot's most preferred option which is still `active`,
+    /// or `None` when every ranked option has been eliminated (an "exhausted" ballot).
+    fn preferred_active_option(&self, active: &[bool]) -> Option<u8> {
+        self.choices
+            .iter()
+            .filter(|choice| {
+                (choice.option_index as usize) < active.len()
+                    && active[choice.option_index as usize]
+            })
+            .min_by_key(|choice| choice.rank)
+            .map(|choice| choice.option_index)
+    }
+}
+
+/// Tabulates the winning option of a ranked (instant-runoff) vote.
+///
+/// Each ballot's weight is counted toward its highest-ranked option which is still in
+/// the running. If no option holds a strict majority (> 50%) of the still-active weight
+/// the option with the least weight is eliminated and its ballots are redistributed to
+/// their next surviving preference. This repeats until an option crosses the majority
+/// threshold or a single option remains.
+///
+/// Edge cases:
+/// * A ballot whose remaining ranks are all eliminated becomes "exhausted" and drops out
+///   of the active denominator.
+/// * An empty or zero-weight ballot contributes nothing and is effectively ignored.
+/// * Ties for elimination are broken deterministically by lowest option index.
+///
+/// Returns the winning option index, or `None` when no weight is cast at all.
+pub fn tabulate_instant_runoff(option_count: usize, ballots: &[RankedBallot]) -> Option<usize> {
+    if option_count == 0 {
+        return None;
+    }
+
+    let mut active = vec![true; option_count];
+    let mut remaining = option_count;
+
+    loop {
+        // Count each ballot toward its highest-ranked surviving option
+        let mut tallies = vec![0u128; option_count];
+        let mut active_weight = 0u128;
+
+        for ballot in ballots {
+            if ballot.weight == 0 {
+                continue;
+            }
+            if let Some(option_index) = ballot.preferred_active_option(&active) {
+                tallies[option_index as usize] += ballot.weight as u128;
+                active_weight += ballot.weight as u128;
+            }
+        }
+
+        // No surviving ballot carries any weight
+        if active_weight == 0 {
+            return None;
+        }
+
+        // The leading option wins outright once it holds a strict majority of active weight
+        let (leader, leader_weight) = tallies
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| active[*index])
+            .max_by(|(a_idx, a_w), (b_idx, b_w)| a_w.cmp(b_w).then(b_idx.cmp(a_idx)))
+            .map(|(index, weight)| (index, *weight))
+            .unwrap();
+
+        if leader_weight * 2 > active_weight || remaining == 1 {
+            return Some(leader);
+        }
+
+        // Eliminate the weakest option, breaking ties by lowest option index
+        let loser = tallies
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| active[*index])
+            .min_by(|(a_idx, a_w), (b_idx, b_w)| a_w.cmp(b_w).then(a_idx.cmp(b_idx)))
+            .map(|(index, _)| index)
+            .unwrap();
+
+        active[loser] = false;
+        remaining -= 1;
+    }
+}
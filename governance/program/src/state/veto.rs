@@ -0,0 +1,31 @@
+//! Veto vote tipping helpers
+
+use crate::state::enums::VoteThreshold;
+
+/// Returns `true` when the accumulated veto weight crosses `veto_vote_threshold`
+/// against the vetoing population's `max_veto_voter_weight`.
+///
+/// A Veto vote is always evaluated as a yes/quorum percentage of the opposite
+/// governing token population; once the threshold is crossed the Proposal is moved to
+/// `ProposalState::Vetoed` by the caller.
+pub fn is_veto_tipped(
+    veto_vote_weight: u64,
+    max_veto_voter_weight: u64,
+    veto_vote_threshold: &VoteThreshold,
+) -> bool {
+    let percentage = match veto_vote_threshold {
+        VoteThreshold::YesVotePercentage(percentage)
+        | VoteThreshold::QuorumPercentage(percentage) => *percentage,
+        VoteThreshold::Disabled => return false,
+    };
+
+    if max_veto_voter_weight == 0 {
+        return false;
+    }
+
+    // Ceil(max * percentage / 100) weight is required to cross the threshold
+    let required_weight =
+        (max_veto_voter_weight as u128 * percentage as u128).div_ceil(100);
+
+    veto_vote_weight as u128 >= required_weight
+}
@@ -0,0 +1,31 @@
+//! VoteRecord account
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+
+use crate::state::ranked_choice::RankedChoice;
+
+/// How a voter cast their vote on a Proposal
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum Vote {
+    // ... existing choices (Approve(Vec<VoteChoice>), Deny) ...
+    /// A Veto vote cast by the governing token population opposite to the one the
+    /// Proposal is being decided by. Counts toward the Proposal's veto threshold only.
+    Veto,
+
+    /// An Abstain vote. It doesn't count toward approval or denial but does count toward
+    /// the Proposal's quorum so turnout requirements can be met without picking a side.
+    Abstain,
+}
+
+/// VoteRecord account V2
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct VoteRecordV2 {
+    // ... existing fields (account_type, proposal, governing_token_owner, is_relinquished,
+    //     voter_weight, vote, reserved_v2) ...
+    /// The voter's ranked ballot, preserved for instant-runoff tabulation at finalize
+    /// time. `None` for Proposals which don't use ranked-choice voting and for non
+    /// `Approve` votes; see `process_cast_vote`.
+    pub ranked_ballot: Option<Vec<RankedChoice>>,
+}
+
+// ... existing helpers (get_vote_record_address_seeds, get_vote_record_data, ...) ...
@@ -0,0 +1,66 @@
+//! Vote weight calculation mode used by Governance when tallying votes
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+
+/// The mode used to transform a voter's resolved `voter_weight` into the weight
+/// which is actually applied to a Proposal's tallies
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum VoteWeightCalculation {
+    /// The resolved voter weight is applied as-is (one token, one vote)
+    Linear,
+
+    /// The resolved voter weight is reduced to its integer square root before
+    /// being applied. This dampens the influence of large token holders.
+    ///
+    /// Note: Quadratic weighting only *mitigates* — it doesn't eliminate — sybil
+    /// attacks, because a whale can split a balance across wallets. It is therefore
+    /// meant to be paired with a voter-weight addin which establishes unique identity.
+    Quadratic,
+}
+
+impl Default for VoteWeightCalculation {
+    fn default() -> Self {
+        VoteWeightCalculation::Linear
+    }
+}
+
+impl VoteWeightCalculation {
+    /// Applies the calculation mode to a single voter's resolved weight before it is
+    /// accumulated into the Proposal tallies.
+    ///
+    /// This transform is applied *per voter*, never to an aggregate. `max_voter_weight`
+    /// is deliberately left untransformed: since `sqrt(wᵢ) <= wᵢ` for every integer
+    /// weight, the sum of the transformed per-voter weights never exceeds the linear
+    /// supply total, so the raw maximum remains the correct bound for the tipping
+    /// thresholds. Square-rooting the supply total would instead make a Quadratic realm
+    /// tip on negligible turnout.
+    pub fn apply_weight(&self, weight: u64) -> u64 {
+        match self {
+            VoteWeightCalculation::Linear => weight,
+            VoteWeightCalculation::Quadratic => isqrt(weight as u128) as u64,
+        }
+    }
+}
+
+/// Returns the integer square root (`floor(sqrt(n))`) of `n` using Newton's method.
+///
+/// The iteration is performed on `u128` to avoid the precision loss and overflow which
+/// would occur with floating point on large token supplies. Starting from `x = n` the
+/// estimate `x = (x + n / x) / 2` is refined until it stops decreasing, at which point
+/// `x` is `floor(sqrt(n))`.
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    let mut x = n;
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            break;
+        }
+        x = next;
+    }
+
+    x
+}